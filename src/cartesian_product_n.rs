@@ -0,0 +1,122 @@
+//! Cartesian products of a fixed number of iterators, as free functions.
+//!
+//! These are built from the same parts as the `iproduct!()` macro.
+
+use adaptors::{cartesian_product, Product};
+use cons_tuples_impl::{cons_tuples, ConsTuples};
+
+/// The iterator returned by [`cartesian_product2`](fn.cartesian_product2.html).
+pub type CartesianProduct2<A, B> = Product<A, B>;
+
+/// Create an iterator over the “cartesian product” of two iterables.
+///
+/// Iterator element type is `(A::Item, B::Item)`. The elements of `a` are
+/// cloned, and `b` is restarted by cloning its iterator.
+///
+/// `IntoIterator` enabled version of `a.cartesian_product(b)`.
+///
+/// ```
+/// use itertools::cartesian_product2;
+///
+/// itertools::assert_equal(cartesian_product2(0..2, "ab".chars()),
+///                         vec![(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b')]);
+/// ```
+pub fn cartesian_product2<A, B>(a: A, b: B) -> CartesianProduct2<A::IntoIter, B::IntoIter>
+    where A: IntoIterator,
+          A::Item: Clone,
+          B: IntoIterator,
+          B::IntoIter: Clone,
+{
+    cartesian_product(a.into_iter(), b.into_iter())
+}
+
+macro_rules! cartesian_product_fn {
+    ($(#[$alias_attr:meta])* type $alias:ident = $prev_alias:ident;
+     $(#[$fn_attr:meta])* fn $name:ident = $prev_name:ident;
+     $A:ident $a:ident, $($B:ident $b:ident,)* ; $Z:ident $z:ident) => {
+        $(#[$alias_attr])*
+        pub type $alias<$A, $($B,)* $Z> =
+            ConsTuples<Product<$prev_alias<$A, $($B),*>, $Z>,
+                       ((<$A as Iterator>::Item, $(<$B as Iterator>::Item,)*),
+                        <$Z as Iterator>::Item)>;
+
+        $(#[$fn_attr])*
+        pub fn $name<$A, $($B,)* $Z>($a: $A, $($b: $B,)* $z: $Z)
+            -> $alias<$A::IntoIter, $($B::IntoIter,)* $Z::IntoIter>
+            where $A: IntoIterator,
+                  $A::Item: Clone,
+                  $($B: IntoIterator,
+                    $B::IntoIter: Clone,
+                    $B::Item: Clone,)*
+                  $Z: IntoIterator,
+                  $Z::IntoIter: Clone,
+        {
+            cons_tuples(cartesian_product($prev_name($a, $($b),*), $z.into_iter()))
+        }
+    }
+}
+
+cartesian_product_fn!(
+    /// The iterator returned by [`cartesian_product3`](fn.cartesian_product3.html).
+    type CartesianProduct3 = CartesianProduct2;
+    /// Create an iterator over the “cartesian product” of three iterables.
+    ///
+    /// Iterator element type is `(A::Item, B::Item, C::Item)`.
+    ///
+    /// Only the first iterable's iterator does not need to be `Clone`, and
+    /// only the last iterable's elements do not need to be `Clone`.
+    ///
+    /// ```
+    /// use itertools::cartesian_product3;
+    ///
+    /// let mut it = cartesian_product3(0..2, "ab".chars(), &[true]);
+    /// assert_eq!(it.next(), Some((0, 'a', &true)));
+    /// assert_eq!(it.next(), Some((0, 'b', &true)));
+    /// ```
+    fn cartesian_product3 = cartesian_product2;
+    A a, B b, ; C c);
+
+cartesian_product_fn!(
+    /// The iterator returned by [`cartesian_product4`](fn.cartesian_product4.html).
+    type CartesianProduct4 = CartesianProduct3;
+    /// Create an iterator over the “cartesian product” of four iterables.
+    ///
+    /// See [`cartesian_product3`](fn.cartesian_product3.html) for more information.
+    fn cartesian_product4 = cartesian_product3;
+    A a, B b, C c, ; D d);
+
+cartesian_product_fn!(
+    /// The iterator returned by [`cartesian_product5`](fn.cartesian_product5.html).
+    type CartesianProduct5 = CartesianProduct4;
+    /// Create an iterator over the “cartesian product” of five iterables.
+    ///
+    /// See [`cartesian_product3`](fn.cartesian_product3.html) for more information.
+    fn cartesian_product5 = cartesian_product4;
+    A a, B b, C c, D d, ; E e);
+
+cartesian_product_fn!(
+    /// The iterator returned by [`cartesian_product6`](fn.cartesian_product6.html).
+    type CartesianProduct6 = CartesianProduct5;
+    /// Create an iterator over the “cartesian product” of six iterables.
+    ///
+    /// See [`cartesian_product3`](fn.cartesian_product3.html) for more information.
+    fn cartesian_product6 = cartesian_product5;
+    A a, B b, C c, D d, E e, ; F f);
+
+cartesian_product_fn!(
+    /// The iterator returned by [`cartesian_product7`](fn.cartesian_product7.html).
+    type CartesianProduct7 = CartesianProduct6;
+    /// Create an iterator over the “cartesian product” of seven iterables.
+    ///
+    /// See [`cartesian_product3`](fn.cartesian_product3.html) for more information.
+    fn cartesian_product7 = cartesian_product6;
+    A a, B b, C c, D d, E e, F f, ; G g);
+
+cartesian_product_fn!(
+    /// The iterator returned by [`cartesian_product8`](fn.cartesian_product8.html).
+    type CartesianProduct8 = CartesianProduct7;
+    /// Create an iterator over the “cartesian product” of eight iterables.
+    ///
+    /// See [`cartesian_product3`](fn.cartesian_product3.html) for more information.
+    fn cartesian_product8 = cartesian_product7;
+    A a, B b, C c, D d, E e, F f, G g, ; H h);
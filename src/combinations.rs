@@ -1,6 +1,9 @@
 
-use std::ops::Index;
 use std::fmt;
+use std::mem;
+use std::usize;
+
+use lazy_buffer::LazyBuffer;
 
 /// An iterator to iterate through all the `n`-length combinations in an iterator.
 ///
@@ -11,13 +14,14 @@ pub struct Combinations<I: Iterator> {
     indices: Vec<usize>,
     pool: LazyBuffer<I>,
     first: bool,
+    colex: bool,
 }
 
 impl<I> fmt::Debug for Combinations<I>
     where I: Iterator + fmt::Debug,
           I::Item: fmt::Debug,
 {
-    debug_fmt_fields!(Combinations, n, indices, pool, first);
+    debug_fmt_fields!(Combinations, n, indices, pool, first, colex);
 }
 
 /// Create a new `Combinations` from a clonable iterator.
@@ -41,6 +45,7 @@ pub fn combinations<I>(iter: I, n: usize) -> Combinations<I>
         indices: indices,
         pool: pool,
         first: true,
+        colex: false,
     }
 }
 
@@ -61,6 +66,27 @@ impl<I> Iterator for Combinations<I>
             self.first = false;
         } else if self.n == 0 {
             return None;
+        } else if self.colex {
+            // Scan from the start, looking for an index that can be
+            // incremented without reaching the next one
+            let mut i: usize = 0;
+            while i + 1 < self.n && self.indices[i] + 1 == self.indices[i + 1] {
+                i += 1;
+            }
+
+            // Only the last index ever needs more from the iterator
+            if i == self.n - 1 && self.indices[i] == pool_len - 1 {
+                if self.pool.is_done() || !self.pool.get_next() {
+                    // Reached the last combination
+                    return None;
+                }
+            }
+
+            // Increment index, and reset the ones to its left
+            self.indices[i] += 1;
+            for j in 0..i {
+                self.indices[j] = j;
+            }
         } else {
             // Scan from the end, looking for an index to increment
             let mut i: usize = self.n - 1;
@@ -97,69 +123,222 @@ impl<I> Iterator for Combinations<I>
         }
         Some(result)
     }
-}
 
-#[derive(Debug)]
-struct LazyBuffer<I: Iterator> {
-    it: I,
-    done: bool,
-    buffer: Vec<I::Item>,
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.pool.size_hint();
+        let low = match self.remaining_for(low) {
+            Some(low) => low,
+            // The colex count is a difference of binomials, so an overflow
+            // does not mean that the count itself does not fit.
+            None if self.colex => 0,
+            None => usize::MAX,
+        };
+        let hi = hi.and_then(|hi| self.remaining_for(hi));
+        (low, hi)
+    }
 }
 
-impl<I> LazyBuffer<I>
+impl<I> Combinations<I>
     where I: Iterator
 {
-    pub fn new(it: I) -> LazyBuffer<I> {
-        let mut it = it;
-        let mut buffer = Vec::new();
-        let done;
-        if let Some(first) = it.next() {
-            buffer.push(first);
-            done = false;
-        } else {
-            done = true;
-        }
-        LazyBuffer {
-            it: it,
-            done: done,
-            buffer: buffer,
-        }
-    }
-
-    pub fn len(&self) -> usize {
-        self.buffer.len()
+    /// Produce the combinations in co-lexicographic (colex) order instead:
+    /// ordered by their last index first, then by the one before it, and so on.
+    ///
+    /// The set of combinations is the same. By default they are produced in
+    /// lexicographic order of their indices, where the first index only moves
+    /// once the whole iterator has been read. Colex order reads the iterator
+    /// only as far as the last index, so with an infinite iterator every
+    /// combination is still reached.
+    ///
+    /// This restarts the iteration from the first combination.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..4).combinations(2).colex();
+    /// itertools::assert_equal(it, vec![
+    ///     vec![0, 1],
+    ///     vec![0, 2],
+    ///     vec![1, 2],
+    ///     vec![0, 3],
+    ///     vec![1, 3],
+    ///     vec![2, 3],
+    ///     ]);
+    /// ```
+    pub fn colex(mut self) -> Self {
+        let n = self.n;
+        self.reset(n);
+        self.colex = true;
+        self
     }
 
-    pub fn is_done(&self) -> bool {
-        self.done
+    /// Reset to produce the `n`-length combinations of the same pool.
+    fn reset(&mut self, n: usize) {
+        self.n = n;
+        self.indices = (0..n).collect();
+        self.first = true;
+        while self.pool.len() < n && self.pool.get_next() { }
     }
 
-    pub fn get_next(&mut self) -> bool {
-        if self.done {
-            return false;
+    /// Return the number of combinations that are still to be produced,
+    /// assuming the source iterator has `pool_len` elements in total,
+    /// or `None` if it does not fit in a `usize`.
+    fn remaining_for(&self, pool_len: usize) -> Option<usize> {
+        if pool_len == 0 || self.n > pool_len {
+            return Some(0);
         }
-        let next_item = self.it.next();
-        match next_item {
-            Some(x) => {
-                self.buffer.push(x);
-                true
+        if self.first {
+            return checked_binomial(pool_len, self.n);
+        }
+        if self.colex {
+            // The combinations after the current one are counted by the
+            // last position where they differ from it: there they have an
+            // index that is greater, but less than the index that follows.
+            let mut count: usize = 0;
+            for (i, &index) in self.indices.iter().enumerate() {
+                let upper = if i + 1 < self.n { self.indices[i + 1] } else { pool_len };
+                // binomial(index + 1, i + 1) is no greater, so it fits if this does
+                let c = match checked_binomial(upper, i + 1) {
+                    Some(c) => c - checked_binomial(index + 1, i + 1).unwrap(),
+                    None => return None,
+                };
+                match count.checked_add(c) {
+                    Some(sum) => count = sum,
+                    None => return None,
+                }
             }
-            None => {
-                self.done = true;
-                false
+            return Some(count);
+        }
+        // The combinations after the current one are counted by fixing
+        // a prefix of the indices and choosing the rest from the elements
+        // to the right of the position that was incremented.
+        let mut count: usize = 0;
+        for (i, &index) in self.indices.iter().enumerate() {
+            match checked_binomial(pool_len - 1 - index, self.n - i)
+                      .and_then(|c| count.checked_add(c)) {
+                Some(sum) => count = sum,
+                None => return None,
             }
         }
+        Some(count)
+    }
+}
+
+/// An iterator to iterate through the powerset of the elements from an iterator.
+///
+/// See [`.powerset()`](../trait.Itertools.html#method.powerset) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Powerset<I: Iterator> {
+    combs: Combinations<I>,
+    // Number of subsets produced so far
+    pos: usize,
+}
+
+impl<I> fmt::Debug for Powerset<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(Powerset, combs, pos);
+}
+
+/// Create a new `Powerset` from a clonable iterator.
+pub fn powerset<I>(iter: I) -> Powerset<I>
+    where I: Iterator
+{
+    Powerset {
+        combs: combinations(iter, 0),
+        pos: 0,
     }
 }
 
-impl<I> Index<usize> for LazyBuffer<I>
+impl<I> Iterator for Powerset<I>
     where I: Iterator,
-          I::Item: Sized
+          I::Item: Clone
 {
-    type Output = I::Item;
+    type Item = Vec<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let elt = if let Some(elt) = self.combs.next() {
+            Some(elt)
+        } else if self.combs.n < self.combs.pool.len() {
+            // Move on to the subsets with one more element, reusing the pool
+            let n = self.combs.n + 1;
+            self.combs.reset(n);
+            self.combs.next()
+        } else if self.pos == 0 {
+            // The empty set is the only subset of an empty iterator
+            Some(Vec::new())
+        } else {
+            None
+        };
+        if elt.is_some() {
+            self.pos = self.pos.saturating_add(1);
+        }
+        elt
+    }
 
-    fn index<'b>(&'b self, _index: usize) -> &'b I::Item {
-        self.buffer.index(_index)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.combs.pool.size_hint();
+        let low = checked_pow2(low).unwrap_or(usize::MAX).saturating_sub(self.pos);
+        let hi = hi.and_then(checked_pow2).map(|hi| hi.saturating_sub(self.pos));
+        (low, hi)
     }
 }
 
+/// Compute `2` to the power of `n`, or `None` on overflow.
+fn checked_pow2(n: usize) -> Option<usize> {
+    if n < mem::size_of::<usize>() * 8 {
+        Some(1 << n)
+    } else {
+        None
+    }
+}
+
+/// Compute `n` choose `k`, or `None` on overflow.
+pub fn checked_binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    // `n` choose `k` is symmetric in `k` and `n - k`; use the smaller one.
+    let k = if k > n - k { n - k } else { k };
+    let mut c: usize = 1;
+    for i in 0..k {
+        // c * (n - i) is divisible by (i + 1), it is (i + 1) * binomial(n, i + 1).
+        // Divide before multiplying, so that only a result that does not fit
+        // overflows.
+        let g = gcd(c, i + 1);
+        c = match (c / g).checked_mul((n - i) / ((i + 1) / g)) {
+            Some(c) => c,
+            None => return None,
+        };
+    }
+    Some(c)
+}
+
+/// Compute the multinomial coefficient of `counts`: the number of distinct
+/// arrangements of a multiset with these multiplicities, or `None` on overflow.
+pub fn checked_multinomial<I>(counts: I) -> Option<usize>
+    where I: IntoIterator<Item = usize>
+{
+    let mut total: usize = 0;
+    let mut m: usize = 1;
+    for count in counts {
+        total = match total.checked_add(count) {
+            Some(total) => total,
+            None => return None,
+        };
+        m = match checked_binomial(total, count).and_then(|c| m.checked_mul(c)) {
+            Some(m) => m,
+            None => return None,
+        };
+    }
+    Some(m)
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
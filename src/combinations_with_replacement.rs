@@ -0,0 +1,131 @@
+
+use std::fmt;
+use std::usize;
+
+use combinations::checked_binomial;
+use lazy_buffer::LazyBuffer;
+
+/// An iterator to iterate through all the `n`-length combinations in an iterator,
+/// with replacement.
+///
+/// See [`.combinations_with_replacement()`](../trait.Itertools.html#method.combinations_with_replacement)
+/// for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct CombinationsWithReplacement<I: Iterator> {
+    n: usize,
+    indices: Vec<usize>,
+    pool: LazyBuffer<I>,
+    first: bool,
+}
+
+impl<I> fmt::Debug for CombinationsWithReplacement<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(CombinationsWithReplacement, n, indices, pool, first);
+}
+
+/// Create a new `CombinationsWithReplacement` from a clonable iterator.
+pub fn combinations_with_replacement<I>(iter: I, n: usize) -> CombinationsWithReplacement<I>
+    where I: Iterator
+{
+    CombinationsWithReplacement {
+        n: n,
+        indices: vec![0; n],
+        pool: LazyBuffer::new(iter),
+        first: true,
+    }
+}
+
+impl<I> Iterator for CombinationsWithReplacement<I>
+    where I: Iterator,
+          I::Item: Clone
+{
+    type Item = Vec<I::Item>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut pool_len = self.pool.len();
+        if self.pool.is_done() && pool_len == 0 && self.n > 0 {
+            return None;
+        }
+
+        if self.first {
+            self.first = false;
+        } else if self.n == 0 {
+            return None;
+        } else {
+            // The last index is the largest one; check if we need to
+            // consume more from the iterator
+            if self.indices[self.n - 1] == pool_len - 1 && !self.pool.is_done() {
+                if self.pool.get_next() {
+                    pool_len += 1;
+                }
+            }
+
+            // Scan from the end, looking for an index to increment
+            let mut i: usize = self.n - 1;
+            while self.indices[i] == pool_len - 1 {
+                if i > 0 {
+                    i -= 1;
+                } else {
+                    // Reached the last combination
+                    return None;
+                }
+            }
+
+            // Increment index, and reset the ones to its right to the same value
+            self.indices[i] += 1;
+            for j in i + 1..self.n {
+                self.indices[j] = self.indices[i];
+            }
+        }
+
+        // Create result vector based on the indices
+        let mut result = Vec::with_capacity(self.n);
+        for i in self.indices.iter() {
+            result.push(self.pool[*i].clone());
+        }
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.pool.size_hint();
+        let low = self.remaining_for(low).unwrap_or(usize::MAX);
+        let hi = hi.and_then(|hi| self.remaining_for(hi));
+        (low, hi)
+    }
+}
+
+impl<I> CombinationsWithReplacement<I>
+    where I: Iterator
+{
+    /// Return the number of combinations that are still to be produced,
+    /// assuming the source iterator has `pool_len` elements in total,
+    /// or `None` if it does not fit in a `usize`.
+    fn remaining_for(&self, pool_len: usize) -> Option<usize> {
+        if self.n == 0 {
+            return Some(self.first as usize);
+        }
+        if pool_len == 0 {
+            return Some(0);
+        }
+        // Adding `i` to the `i`th index maps the combinations with replacement
+        // of `pool_len` elements, in order, to the plain combinations of
+        // `pool_len + n - 1` elements.
+        let total_len = match pool_len.checked_add(self.n - 1) {
+            Some(len) => len,
+            None => return None,
+        };
+        if self.first {
+            return checked_binomial(total_len, self.n);
+        }
+        let mut count: usize = 0;
+        for (i, &index) in self.indices.iter().enumerate() {
+            match checked_binomial(total_len - 1 - (index + i), self.n - i)
+                      .and_then(|c| count.checked_add(c)) {
+                Some(sum) => count = sum,
+                None => return None,
+            }
+        }
+        Some(count)
+    }
+}
@@ -19,8 +19,19 @@ pub use put_back_n_impl::put_back_n;
 #[cfg(feature = "use_std")]
 pub use multipeek_impl::multipeek;
 #[cfg(feature = "use_std")]
+pub use multiset_permutations::multiset_permutations;
+#[cfg(feature = "use_std")]
 pub use kmerge_impl::kmerge;
 pub use zip_eq_impl::zip_eq;
+pub use cartesian_product_n::{
+    cartesian_product2,
+    cartesian_product3,
+    cartesian_product4,
+    cartesian_product5,
+    cartesian_product6,
+    cartesian_product7,
+    cartesian_product8,
+};
 pub use merge_join::merge_join_by;
 #[cfg(feature = "use_std")]
 pub use rciter_impl::rciter;
@@ -0,0 +1,64 @@
+
+use std::fmt;
+use std::iter::Fuse;
+
+/// An iterator adaptor that collects the values of consecutive key-value
+/// pairs with equal keys.
+///
+/// See [`.group_adjacent()`](../trait.Itertools.html#method.group_adjacent) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct GroupAdjacent<I: Iterator> {
+    iter: Fuse<I>,
+    // The first pair of the next run, if it was already read
+    next: Option<I::Item>,
+}
+
+impl<I> fmt::Debug for GroupAdjacent<I>
+    where I: Iterator + fmt::Debug,
+          I::Item: fmt::Debug,
+{
+    debug_fmt_fields!(GroupAdjacent, iter, next);
+}
+
+/// Create a new `GroupAdjacent` iterator.
+pub fn group_adjacent<I>(iter: I) -> GroupAdjacent<I>
+    where I: Iterator
+{
+    GroupAdjacent {
+        iter: iter.fuse(),
+        next: None,
+    }
+}
+
+impl<I, K, V> Iterator for GroupAdjacent<I>
+    where I: Iterator<Item = (K, V)>,
+          K: PartialEq,
+{
+    type Item = (K, Vec<V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = match self.next.take().or_else(|| self.iter.next()) {
+            None => return None,
+            Some(pair) => pair,
+        };
+        let mut values = vec![first];
+        for (k, v) in self.iter.by_ref() {
+            if k == key {
+                values.push(v);
+            } else {
+                self.next = Some((k, v));
+                break;
+            }
+        }
+        Some((key, values))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, hi) = self.iter.size_hint();
+        let pending = self.next.is_some() as usize;
+        // Each run has at least one pair, and any pairs left make at least one run.
+        let low = if low > 0 || pending > 0 { 1 } else { 0 };
+        let hi = hi.and_then(|hi| hi.checked_add(pending));
+        (low, hi)
+    }
+}
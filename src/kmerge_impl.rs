@@ -1,6 +1,5 @@
 
 use size_hint;
-use Itertools;
 
 use std::mem::replace;
 
@@ -170,10 +169,7 @@ impl<I> Iterator for KMerge<I>
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.heap.iter()
-                 .map(|i| i.size_hint())
-                 .fold1(size_hint::add)
-                 .unwrap_or((0, Some(0)))
+        size_hint::sum(self.heap.iter().map(|i| i.size_hint()))
     }
 }
 
@@ -232,9 +228,6 @@ impl<I, F> Iterator for KMergeBy<I, F>
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.heap.iter()
-                 .map(|i| i.size_hint())
-                 .fold1(size_hint::add)
-                 .unwrap_or((0, Some(0)))
+        size_hint::sum(self.heap.iter().map(|i| i.size_hint()))
     }
 }
@@ -66,11 +66,24 @@ pub mod structs {
         Flatten,
         Positions,
     };
+    pub use cartesian_product_n::{
+        CartesianProduct2,
+        CartesianProduct3,
+        CartesianProduct4,
+        CartesianProduct5,
+        CartesianProduct6,
+        CartesianProduct7,
+        CartesianProduct8,
+    };
+    #[cfg(feature = "use_std")]
+    pub use combinations::{Combinations, Powerset};
     #[cfg(feature = "use_std")]
-    pub use combinations::Combinations;
+    pub use combinations_with_replacement::CombinationsWithReplacement;
     pub use cons_tuples_impl::ConsTuples;
     pub use format::{Format, FormatWith};
     #[cfg(feature = "use_std")]
+    pub use group_adjacent_impl::GroupAdjacent;
+    #[cfg(feature = "use_std")]
     pub use groupbylazy::{IntoChunks, Chunk, Chunks, GroupBy, Group, Groups};
     pub use intersperse::Intersperse;
     #[cfg(feature = "use_std")]
@@ -78,6 +91,8 @@ pub mod structs {
     pub use merge_join::MergeJoinBy;
     #[cfg(feature = "use_std")]
     pub use multipeek_impl::MultiPeek;
+    #[cfg(feature = "use_std")]
+    pub use multiset_permutations::MultisetPermutations;
     pub use pad_tail::PadUsing;
     pub use peeking_take_while::PeekingTakeWhile;
     pub use process_results_impl::ProcessResults;
@@ -118,21 +133,30 @@ pub use either_or_both::EitherOrBoth;
 pub mod free;
 #[doc(inline)]
 pub use free::*;
+mod cartesian_product_n;
 mod concat_impl;
 mod cons_tuples_impl;
 #[cfg(feature = "use_std")]
 mod combinations;
+#[cfg(feature = "use_std")]
+mod combinations_with_replacement;
 mod diff;
 mod format;
 #[cfg(feature = "use_std")]
+mod group_adjacent_impl;
+#[cfg(feature = "use_std")]
 mod groupbylazy;
 mod intersperse;
 #[cfg(feature = "use_std")]
 mod kmerge_impl;
+#[cfg(feature = "use_std")]
+mod lazy_buffer;
 mod merge_join;
 mod minmax;
 #[cfg(feature = "use_std")]
 mod multipeek_impl;
+#[cfg(feature = "use_std")]
+mod multiset_permutations;
 mod pad_tail;
 mod peeking_take_while;
 mod process_results_impl;
@@ -450,6 +474,35 @@ pub trait Itertools : Iterator {
         groupbylazy::new(self, key)
     }
 
+    /// Return an iterator adaptor that collects the values of consecutive
+    /// key-value pairs with equal keys (“runs”) into a vector.
+    ///
+    /// This is meant for input that is already sorted or grouped by key:
+    /// only adjacent pairs are grouped, so a key that appears in several
+    /// runs is produced once per run. The runs are produced in input order,
+    /// and keys are only compared for equality, not hashed.
+    ///
+    /// Iterator element type is `(K, Vec<V>)`.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e')];
+    /// itertools::assert_equal(data.into_iter().group_adjacent(),
+    ///                         vec![(1, vec!['a', 'b']),
+    ///                              (2, vec!['c']),
+    ///                              (3, vec!['d', 'e'])]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn group_adjacent<K, V>(self) -> GroupAdjacent<Self>
+        where Self: Sized + Iterator<Item = (K, V)>,
+              K: PartialEq,
+    {
+        group_adjacent_impl::group_adjacent(self)
+    }
+
     /// Return an *iterable* that can chunk the iterator.
     ///
     /// Yield subiterators (chunks) that each yield a fixed number elements,
@@ -1022,6 +1075,68 @@ pub trait Itertools : Iterator {
         combinations::combinations(self, n)
     }
 
+    /// Return an iterator adaptor that iterates over the `n`-length combinations of
+    /// the elements from an iterator, where each element may be picked more
+    /// than once.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`. The iterator produces a new Vec per iteration,
+    /// and clones the iterator elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..4).combinations_with_replacement(2);
+    /// itertools::assert_equal(it, vec![
+    ///     vec![1, 1],
+    ///     vec![1, 2],
+    ///     vec![1, 3],
+    ///     vec![2, 2],
+    ///     vec![2, 3],
+    ///     vec![3, 3],
+    ///     ]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn combinations_with_replacement(self, n: usize) -> CombinationsWithReplacement<Self>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        combinations_with_replacement::combinations_with_replacement(self, n)
+    }
+
+    /// Return an iterator adaptor that iterates over all subsets of the
+    /// elements from an iterator, the powerset.
+    ///
+    /// The subsets are produced in order of increasing length, starting
+    /// with the empty set, and each length's subsets are in the order of
+    /// [`.combinations()`](#method.combinations). The elements are
+    /// buffered only once, as they are needed.
+    ///
+    /// Iterator element type is `Vec<Self::Item>`. The iterator produces a new Vec per iteration,
+    /// and clones the iterator elements.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..4).powerset();
+    /// itertools::assert_equal(it, vec![
+    ///     vec![],
+    ///     vec![1],
+    ///     vec![2],
+    ///     vec![3],
+    ///     vec![1, 2],
+    ///     vec![1, 3],
+    ///     vec![2, 3],
+    ///     vec![1, 2, 3],
+    ///     ]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn powerset(self) -> Powerset<Self>
+        where Self: Sized,
+              Self::Item: Clone
+    {
+        combinations::powerset(self)
+    }
+
     /// Return an iterator adaptor that pads the sequence to a minimum length of
     /// `min` by filling missing elements using a closure `f`.
     ///
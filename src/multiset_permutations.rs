@@ -0,0 +1,123 @@
+
+use std::fmt;
+use std::usize;
+
+use combinations::checked_multinomial;
+
+/// An iterator over the distinct arrangements of a multiset.
+///
+/// See [`multiset_permutations()`](fn.multiset_permutations.html) for more information.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct MultisetPermutations<'a, T: 'a> {
+    items: &'a [(T, usize)],
+    // The index in `items` of each element of the current arrangement
+    indices: Vec<usize>,
+    first: bool,
+    // Number of arrangements still to be produced, if it fits in a usize
+    remaining: Option<usize>,
+}
+
+impl<'a, T> fmt::Debug for MultisetPermutations<'a, T>
+    where T: fmt::Debug,
+{
+    debug_fmt_fields!(MultisetPermutations, items, indices, first, remaining);
+}
+
+impl<'a, T> Clone for MultisetPermutations<'a, T> {
+    fn clone(&self) -> Self {
+        MultisetPermutations {
+            items: self.items,
+            indices: self.indices.clone(),
+            first: self.first,
+            remaining: self.remaining,
+        }
+    }
+}
+
+/// Iterate over the distinct arrangements (permutations) of a multiset,
+/// given as pairs of a value and how many times it occurs.
+///
+/// Each arrangement is produced exactly once, in lexicographic order of the
+/// positions of the values in `items`. The number of arrangements is the
+/// multinomial coefficient of the counts. This is much less work than
+/// permuting the flattened list and skipping the repeats.
+///
+/// The values are told apart by their position in `items`, they are not
+/// compared, so a value should appear in only one pair.
+///
+/// Iterator element type is `Vec<T>`. The iterator produces a new Vec per
+/// iteration, and clones the values.
+///
+/// ```
+/// use itertools::multiset_permutations;
+///
+/// itertools::assert_equal(multiset_permutations(&[('a', 2), ('b', 1)]),
+///                         vec![vec!['a', 'a', 'b'],
+///                              vec!['a', 'b', 'a'],
+///                              vec!['b', 'a', 'a']]);
+/// ```
+pub fn multiset_permutations<'a, T>(items: &'a [(T, usize)]) -> MultisetPermutations<'a, T>
+    where T: Clone
+{
+    let mut indices = Vec::new();
+    for (i, &(_, count)) in items.iter().enumerate() {
+        for _ in 0..count {
+            indices.push(i);
+        }
+    }
+    MultisetPermutations {
+        items: items,
+        indices: indices,
+        first: true,
+        remaining: checked_multinomial(items.iter().map(|&(_, count)| count)),
+    }
+}
+
+impl<'a, T> Iterator for MultisetPermutations<'a, T>
+    where T: Clone
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first {
+            self.first = false;
+        } else if !next_permutation(&mut self.indices) {
+            return None;
+        }
+        self.remaining = self.remaining.map(|n| n - 1);
+        Some(self.indices.iter().map(|&i| self.items[i].0.clone()).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+/// Rearrange `v` into the lexicographically next greater permutation and
+/// return `true`, or leave it unchanged and return `false` if it is the
+/// greatest one.
+fn next_permutation(v: &mut [usize]) -> bool {
+    // Find the last ascent
+    let mut i = v.len();
+    loop {
+        if i < 2 {
+            return false;
+        }
+        i -= 1;
+        if v[i - 1] < v[i] {
+            break;
+        }
+    }
+    // Swap the element before it with the last element that is greater,
+    // then put the (descending) tail in ascending order.
+    let mut j = v.len() - 1;
+    while v[j] <= v[i - 1] {
+        j -= 1;
+    }
+    v.swap(i - 1, j);
+    v[i..].reverse();
+    true
+}
@@ -65,15 +65,108 @@ pub fn mul(a: SizeHint, b: SizeHint) -> SizeHint {
     (low, hi)
 }
 
-/// Multiply **x** correctly with a **SizeHint**.
+/// Multiply **x** correctly with a **SizeHint**, for example when an adaptor
+/// produces **x** elements for each element.
+///
+/// Multiplying by zero gives the exact size zero, even if the upper bound was
+/// unknown.
+///
+/// ```ignore
+/// use std::usize;
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::mul_scalar((3, Some(4)), 2), (6, Some(8)));
+///
+/// assert_eq!(size_hint::mul_scalar((3, Some(usize::MAX)), 2),
+///            (6, None));
+///
+/// assert_eq!(size_hint::mul_scalar((usize::MAX, None), 2),
+///            (usize::MAX, None));
+///
+/// assert_eq!(size_hint::mul_scalar((3, None), 0), (0, Some(0)));
+/// ```
 #[inline]
 pub fn mul_scalar(sh: SizeHint, x: usize) -> SizeHint {
     let (mut low, mut hi) = sh;
     low = low.saturating_mul(x);
-    hi = hi.and_then(|elt| elt.checked_mul(x));
+    hi = if x == 0 { Some(0) } else { hi.and_then(|elt| elt.checked_mul(x)) };
     (low, hi)
 }
 
+/// Add all the **SizeHint**s from an iterator correctly.
+///
+/// The sum of no hints is the exact size zero.
+///
+/// ```ignore
+/// use std::usize;
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::sum(vec![(1, Some(2)), (3, Some(4)), (5, None)]),
+///            (9, None));
+///
+/// assert_eq!(size_hint::sum(vec![(usize::MAX, Some(usize::MAX)), (1, Some(1))]),
+///            (usize::MAX, None));
+///
+/// assert_eq!(size_hint::sum(vec![]), (0, Some(0)));
+/// ```
+#[inline]
+#[allow(dead_code)]
+pub fn sum<I>(hints: I) -> SizeHint
+    where I: IntoIterator<Item = SizeHint>
+{
+    hints.into_iter().fold((0, Some(0)), add)
+}
+
+/// Multiply all the **SizeHint**s from an iterator correctly.
+///
+/// The product of no hints is the exact size one.
+///
+/// ```ignore
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::product(vec![(2, Some(2)), (3, Some(4)), (1, Some(5))]),
+///            (6, Some(40)));
+///
+/// assert_eq!(size_hint::product(vec![(2, Some(2)), (3, None)]),
+///            (6, None));
+///
+/// assert_eq!(size_hint::product(vec![(2, None), (0, Some(0))]),
+///            (0, Some(0)));
+///
+/// assert_eq!(size_hint::product(vec![]), (1, Some(1)));
+/// ```
+#[inline]
+#[allow(dead_code)]
+pub fn product<I>(hints: I) -> SizeHint
+    where I: IntoIterator<Item = SizeHint>
+{
+    hints.into_iter().fold((1, Some(1)), mul)
+}
+
+/// Divide a **SizeHint** correctly by **x**, for example when an adaptor
+/// produces one element for each group of **x** elements.
+///
+/// Both bounds are rounded down, since an incomplete group produces nothing.
+///
+/// **x** must not be zero.
+///
+/// ```ignore
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::div((6, Some(9)), 3), (2, Some(3)));
+///
+/// assert_eq!(size_hint::div((7, Some(11)), 3), (2, Some(3)));
+///
+/// assert_eq!(size_hint::div((5, None), 2), (2, None));
+/// ```
+#[inline]
+#[allow(dead_code)]
+pub fn div(sh: SizeHint, x: usize) -> SizeHint {
+    debug_assert!(x != 0, "size_hint::div: division by zero");
+    let (low, hi) = sh;
+    (low / x, hi.map(|elt| elt / x))
+}
+
 /// Return the maximum
 #[inline]
 pub fn max(a: SizeHint, b: SizeHint) -> SizeHint {
@@ -540,6 +540,21 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn size_group_adjacent(it: Iter<u8>) -> bool {
+        correct_size_hint(it.map(|x| (x / 4, x)).group_adjacent())
+    }
+}
+
+quickcheck! {
+    fn equal_group_adjacent(a: Vec<(u8, u8)>) -> bool {
+        let runs = a.iter().group_by(|&&(k, _)| k).into_iter()
+            .map(|(k, run)| (k, run.map(|&(_, v)| v).collect_vec()))
+            .collect_vec();
+        itertools::equal(a.into_iter().group_adjacent(), runs)
+    }
+}
+
 quickcheck! {
     fn equal_dedup(a: Vec<i32>) -> bool {
         let mut b = a.clone();
@@ -628,6 +643,30 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    fn size_combinations_vec(it: Iter<u8>, k: u8) -> bool {
+        correct_size_hint(it.combinations(k as usize % 3))
+    }
+}
+
+quickcheck! {
+    fn size_combinations_colex(it: Iter<u8>, k: u8) -> bool {
+        correct_size_hint(it.combinations(k as usize % 3).colex())
+    }
+}
+
+quickcheck! {
+    fn size_combinations_with_replacement(it: Iter<u8>, k: u8) -> bool {
+        correct_size_hint(it.combinations_with_replacement(k as usize % 3))
+    }
+}
+
+quickcheck! {
+    fn size_powerset(it: Iter<u8>, n: u8) -> bool {
+        correct_size_hint(it.take(n as usize % 8).powerset())
+    }
+}
+
 quickcheck! {
     fn equal_combinations(it: Iter<i16>) -> bool {
         let values = it.clone().collect_vec();
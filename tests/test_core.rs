@@ -36,6 +36,29 @@ fn product_temporary() {
 }
 
 
+#[test]
+fn cartesian_product_fns() {
+    let mut prod = it::cartesian_product2(0..2, &['α', 'β']);
+    assert_eq!(prod.size_hint(), (4, Some(4)));
+    assert!(prod.next() == Some((0, &'α')));
+    assert!(prod.next() == Some((0, &'β')));
+    assert!(prod.next() == Some((1, &'α')));
+    assert!(prod.next() == Some((1, &'β')));
+    assert!(prod.next() == None);
+
+    // the first iterator is never restarted, so it does not need to be Clone
+    let mut once = Some(1u8);
+    let first = it::repeat_call(move || once.take()).while_some();
+    let prod = it::cartesian_product3(first, "ab".chars(), 0..2i64);
+    it::assert_equal(prod, [(1, 'a', 0), (1, 'a', 1), (1, 'b', 0), (1, 'b', 1)].iter().cloned());
+
+    let prod = it::cartesian_product3(0..2, 0..3, 0..4);
+    it::assert_equal(prod, iproduct!(0..2, 0..3, 0..4));
+
+    let prod = it::cartesian_product8(0..2, 0..2, 0..2, 0..2, 0..2, 0..2, 0..2, 0..2);
+    assert_eq!(prod.count(), 256);
+}
+
 #[test]
 fn izip_macro() {
     let mut zip = izip!(2..3);
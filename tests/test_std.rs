@@ -51,6 +51,13 @@ fn interleave_shortest() {
     let i1 = ::std::iter::repeat(1);
     let it = v0.into_iter().interleave_shortest(i1);
     assert_eq!(it.size_hint(), (6, Some(6)));
+
+    let it = (0..3).filter(|_| true).interleave_shortest(0..);
+    assert_eq!(it.size_hint(), (0, Some(6)));
+
+    // twice the shorter length does not fit in a usize
+    let it = (0..::std::usize::MAX).interleave_shortest(0..::std::usize::MAX);
+    assert_eq!(it.size_hint(), (::std::usize::MAX, None));
 }
 
 
@@ -243,14 +250,36 @@ fn kmerge_empty() {
 
 #[test]
 fn kmerge_size_hint() {
+    use std::usize;
+
     let its = (0..5).map(|_| (0..10));
     assert_eq!(its.kmerge().size_hint(), (50, Some(50)));
+
+    let mut it = vec![vec![1, 3], vec![2]].into_iter().kmerge();
+    it.next();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+
+    // each head is known to exist, the rest of each source is unknown
+    let it = (1..3).map(|n| (0..n).filter(|_| true)).kmerge();
+    assert_eq!(it.size_hint(), (2, Some(3)));
+
+    let it = vec![0..usize::MAX, 0..2].into_iter().kmerge();
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn kmerge_by_size_hint() {
+    let it = vec![vec![3, 1], vec![2]].into_iter().kmerge_by(|a, b| a > b);
+    assert_eq!(it.size_hint(), (3, Some(3)));
 }
 
 #[test]
 fn kmerge_empty_size_hint() {
     let its = (0..5).map(|_| (0..0));
     assert_eq!(its.kmerge().size_hint(), (0, Some(0)));
+
+    let none: Vec<Vec<i32>> = Vec::new();
+    assert_eq!(none.into_iter().kmerge().size_hint(), (0, Some(0)));
 }
 
 #[test]
@@ -520,6 +549,22 @@ fn flatten_fold() {
     assert_eq!(&xs_d[..], &xs[1..]);
 }
 
+#[test]
+fn group_adjacent() {
+    let data = vec![("a", 1), ("a", 2), ("b", 3), ("c", 4), ("c", 5), ("c", 6)];
+    it::assert_equal(data.into_iter().group_adjacent(),
+                     vec![("a", vec![1, 2]),
+                          ("b", vec![3]),
+                          ("c", vec![4, 5, 6])]);
+
+    // only runs are grouped
+    let data = vec![(1, 'x'), (2, 'y'), (1, 'z')];
+    it::assert_equal(data.into_iter().group_adjacent(),
+                     vec![(1, vec!['x']), (2, vec!['y']), (1, vec!['z'])]);
+
+    it::assert_equal(Vec::<(u8, u8)>::new().into_iter().group_adjacent(), vec![]);
+}
+
 #[test]
 fn combinations() {
     assert!((1..3).combinations(5).next().is_none());
@@ -563,6 +608,144 @@ fn combinations_zero() {
     it::assert_equal((1..3).combinations(0), vec![vec![]]);
 }
 
+#[test]
+fn combinations_size_hint() {
+    let mut it = (0..4).combinations(2);
+    assert_eq!(it.size_hint(), (6, Some(6)));
+    let mut seen = Vec::new();
+    while let Some(c) = it.next() {
+        seen.push(c);
+        assert_eq!(it.size_hint(), (6 - seen.len(), Some(6 - seen.len())));
+    }
+    assert_eq!(seen, vec![
+        vec![0, 1],
+        vec![0, 2],
+        vec![0, 3],
+        vec![1, 2],
+        vec![1, 3],
+        vec![2, 3],
+        ]);
+
+    // the count does not fit in a usize
+    let (_, hi) = (0..100).combinations(50).size_hint();
+    assert_eq!(hi, None);
+}
+
+#[test]
+fn combinations_colex() {
+    let it = (0..4).combinations(2).colex();
+    it::assert_equal(it, vec![
+        vec![0, 1],
+        vec![0, 2],
+        vec![1, 2],
+        vec![0, 3],
+        vec![1, 3],
+        vec![2, 3],
+        ]);
+
+    let mut it = (0..5).combinations(3).colex();
+    for i in 0..10 {
+        assert_eq!(it.size_hint(), (10 - i, Some(10 - i)));
+        it.next();
+    }
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+
+    it::assert_equal((0..3).combinations(0).colex(), vec![vec![]]);
+    it::assert_equal((0..2).combinations(3).colex(), <Vec<Vec<_>>>::new());
+    // every combination is reached, even from an infinite iterator
+    assert_eq!((0..).combinations(2).colex().nth(5), Some(vec![2, 3]));
+}
+
+#[test]
+fn combinations_with_replacement() {
+    let it = (0..3).combinations_with_replacement(2);
+    it::assert_equal(it, vec![
+        vec![0, 0],
+        vec![0, 1],
+        vec![0, 2],
+        vec![1, 1],
+        vec![1, 2],
+        vec![2, 2],
+        ]);
+
+    it::assert_equal((0..2).combinations_with_replacement(3), vec![
+        vec![0, 0, 0],
+        vec![0, 0, 1],
+        vec![0, 1, 1],
+        vec![1, 1, 1],
+        ]);
+
+    it::assert_equal((0..0).combinations_with_replacement(2), <Vec<Vec<_>>>::new());
+    let mut it = (0..0).combinations_with_replacement(2);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    it::assert_equal((0..1).combinations_with_replacement(3), vec![vec![0, 0, 0]]);
+    it::assert_equal((0..3).combinations_with_replacement(0), vec![vec![]]);
+}
+
+#[test]
+fn combinations_with_replacement_size_hint() {
+    let mut it = (0..3).combinations_with_replacement(2);
+    for i in 0..6 {
+        assert_eq!(it.size_hint(), (6 - i, Some(6 - i)));
+        it.next();
+    }
+    assert_eq!(it.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn powerset() {
+    let it = (0..3).powerset();
+    assert_eq!(it.size_hint(), (8, Some(8)));
+    it::assert_equal(it, vec![
+        vec![],
+        vec![0],
+        vec![1],
+        vec![2],
+        vec![0, 1],
+        vec![0, 2],
+        vec![1, 2],
+        vec![0, 1, 2],
+        ]);
+
+    it::assert_equal((0..0).powerset(), vec![vec![]]);
+    it::assert_equal((0..1).powerset(), vec![vec![], vec![0]]);
+    assert_eq!((0..10).powerset().count(), 1 << 10);
+
+    let mut it = (0..4).powerset();
+    for i in 0..16 {
+        assert_eq!(it.size_hint(), (16 - i, Some(16 - i)));
+        it.next();
+    }
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!((0..100).powerset().size_hint().1, None);
+}
+
+#[test]
+fn multiset_permutations() {
+    let items = [('a', 2), ('b', 1)];
+    let mut it = it::multiset_permutations(&items);
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    it.next();
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    it::assert_equal(it::multiset_permutations(&items), vec![
+        vec!['a', 'a', 'b'],
+        vec!['a', 'b', 'a'],
+        vec!['b', 'a', 'a'],
+        ]);
+
+    let items = [(0, 2), (1, 0), (2, 2)];
+    let mut it = it::multiset_permutations(&items);
+    assert_eq!(it.by_ref().count(), 6);
+    assert_eq!(it.next(), None);
+
+    let none: [(u8, usize); 0] = [];
+    it::assert_equal(it::multiset_permutations(&none), vec![vec![]]);
+    // the count does not fit in a usize
+    assert_eq!(it::multiset_permutations(&[(0, 40), (1, 40)]).size_hint().1, None);
+}
+
 #[test]
 fn diff_mismatch() {
     let a = vec![1, 2, 3, 4];